@@ -0,0 +1,163 @@
+extern crate serde_json;
+
+use std::ops::{Deref, DerefMut};
+use std::io::{self, Cursor, Read};
+use std::fmt;
+
+use rocket::outcome::Outcome;
+use rocket::request::Request;
+use rocket::data::{self, Data, FromData};
+use rocket::response::{self, Responder, Response};
+use rocket::http::{ContentType, Status};
+
+use serde::{Serialize, Deserialize};
+
+/// The error type returned when parsing a `Json<T>` from incoming data
+/// fails, distinguishing a body that exceeded the size limit from one that
+/// was read in full but couldn't be parsed.
+#[derive(Debug)]
+pub enum JsonError {
+    /// The incoming data exceeded the configured size limit.
+    TooLarge,
+    /// An I/O error occurred while reading the incoming data.
+    Io(io::Error),
+    /// The incoming data could not be parsed as valid JSON.
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsonError::TooLarge => write!(f, "incoming data exceeded the size limit"),
+            JsonError::Io(ref e) => write!(f, "i/o error reading data: {}", e),
+            JsonError::Parse(ref e) => write!(f, "invalid JSON: {}", e),
+        }
+    }
+}
+
+/// The `Json` type: implements `FromData` and `Responder`, allowing you to
+/// easily consume and respond with JSON data.
+///
+/// If you're receiving JSON data, simply add a `data` parameter to your
+/// route arguments and ensure the type of the parameter is a `Json<T>`,
+/// where `T` is some type you'd like to parse from JSON. `T` must implement
+/// `Deserialize` from [Serde](https://github.com/serde-rs/serde). The data
+/// is parsed from the HTTP request body.
+///
+/// ```rust,ignore
+/// #[post("/users/", format = "application/json", data = "<user>")]
+/// fn new_user(user: Json<User>) {
+///     ...
+/// }
+/// ```
+///
+/// If you're responding with JSON data, return a `Json<T>` type, where `T`
+/// implements `Serialize` from [Serde](https://github.com/serde-rs/serde).
+/// The content type of the response is set to `application/json`
+/// automatically.
+///
+/// ```rust,ignore
+/// #[get("/users/<id>")]
+/// fn user(id: usize) -> Json<User> {
+///     let user_from_id = User::from(id);
+///     ...
+///     Json(user_from_id)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    /// Consumes the `Json` wrapper and returns the wrapped item.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Default maximum size, in bytes, of incoming JSON data: 1MiB.
+const DEFAULT_MAX_SIZE: u64 = 1048576;
+
+/// Returns the maximum number of bytes to read for incoming JSON data, as
+/// configured by the `limits.json` parameter in `Rocket.toml`, or
+/// `DEFAULT_MAX_SIZE` if the parameter isn't set or isn't a valid,
+/// non-negative integer.
+fn max_size(request: &Request) -> u64 {
+    match request.config().get_int("limits.json") {
+        Ok(n) if n >= 0 => n as u64,
+        Ok(n) => {
+            warn_!("`limits.json` is negative ({}); using default.", n);
+            DEFAULT_MAX_SIZE
+        }
+        Err(_) => DEFAULT_MAX_SIZE,
+    }
+}
+
+impl<T: Deserialize> FromData for Json<T> {
+    type Error = JsonError;
+
+    fn from_data(request: &Request, data: Data) -> data::Outcome<Self, Self::Error> {
+        if !request.content_type().map_or(false, |ct| ct.is_json()) {
+            error_!("Content-Type is not JSON.");
+            return Outcome::Forward(data);
+        }
+
+        let limit = max_size(request);
+        let mut reader = data.open().take(limit);
+        let mut buf = Vec::new();
+        if let Err(e) = reader.by_ref().read_to_end(&mut buf) {
+            error_!("Couldn't read request data: {:?}", e);
+            return Outcome::Failure((Status::BadRequest, JsonError::Io(e)));
+        }
+
+        // If we read exactly up to the limit, the body may have been
+        // truncated; check whether any data remains beyond the cap.
+        if buf.len() as u64 == limit {
+            let mut probe = [0u8; 1];
+            let truncated = reader.into_inner().read(&mut probe).map(|n| n > 0).unwrap_or(false);
+            if truncated {
+                error_!("JSON data exceeds the {} byte size limit.", limit);
+                return Outcome::Failure((Status::PayloadTooLarge, JsonError::TooLarge));
+            }
+        }
+
+        match serde_json::from_slice(&buf).map(|val| Json(val)) {
+            Ok(value) => Outcome::Success(value),
+            Err(e) => {
+                error_!("Couldn't parse JSON body: {:?}", e);
+                Outcome::Failure((Status::BadRequest, JsonError::Parse(e)))
+            }
+        }
+    }
+}
+
+/// Serializes the wrapped value into JSON. Returns a response with
+/// Content-Type JSON and a fixed-size body with the serialization. If
+/// serialization fails, an `Err` of `Status::InternalServerError` is
+/// returned.
+impl<T: Serialize> Responder<'static> for Json<T> {
+    fn respond(self) -> response::Result<'static> {
+        serde_json::to_vec(&self.0).map_err(|e| {
+            error_!("Json failed to serialize: {:?}", e);
+            Status::InternalServerError
+        }).and_then(|buf| {
+            Response::build()
+                .header(ContentType::JSON)
+                .sized_body(Cursor::new(buf))
+                .ok()
+        })
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref<'a>(&'a self) -> &'a T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut<'a>(&'a mut self) -> &'a mut T {
+        &mut self.0
+    }
+}
@@ -0,0 +1,42 @@
+//! This crate contains officially sanctioned contributor libraries that
+//! provide functionality commonly used by Rocket applications. These
+//! libraries are always kept in-sync with the core Rocket library, and they
+//! are gated behind a Cargo feature so that downstream crates only pay for
+//! the dependencies of the features they actually use.
+//!
+//! The recommended way to include features from this crate is to select the
+//! features you'd like to use in `Cargo.toml`:
+//!
+//! ```toml
+//! [dependencies.rocket_contrib]
+//! version = "*"
+//! default-features = false
+//! features = ["json"]
+//! ```
+
+extern crate rocket;
+extern crate serde;
+
+#[cfg(feature = "pattern")]
+#[macro_use]
+extern crate lazy_static;
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::{Json, JsonError};
+
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "msgpack")]
+pub use msgpack::{MsgPack, NamedMsgPack, MsgPackError};
+
+#[cfg(feature = "pattern")]
+mod pattern;
+#[cfg(feature = "pattern")]
+pub use pattern::{Matcher, Pattern};
+
+#[cfg(all(feature = "json", feature = "msgpack"))]
+mod negotiated;
+#[cfg(all(feature = "json", feature = "msgpack"))]
+pub use negotiated::{Negotiated, Preferred};
@@ -1,7 +1,8 @@
 extern crate rmp_serde;
 
 use std::ops::{Deref, DerefMut};
-use std::io::{Cursor, Read};
+use std::io::{self, Cursor, Read};
+use std::fmt;
 
 use rocket::outcome::Outcome;
 use rocket::request::Request;
@@ -11,7 +12,28 @@ use rocket::http::{ContentType, Status};
 
 use serde::{Serialize, Deserialize};
 
-pub use self::rmp_serde::decode::Error as MsgPackError;
+/// The error type returned when parsing a `MsgPack<T>` from incoming data
+/// fails, distinguishing a body that exceeded the size limit from one that
+/// was read in full but couldn't be parsed.
+#[derive(Debug)]
+pub enum MsgPackError {
+    /// The incoming data exceeded the configured size limit.
+    TooLarge,
+    /// An I/O error occurred while reading the incoming data.
+    Io(io::Error),
+    /// The incoming data could not be parsed as valid MessagePack.
+    Parse(rmp_serde::decode::Error),
+}
+
+impl fmt::Display for MsgPackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MsgPackError::TooLarge => write!(f, "incoming data exceeded the size limit"),
+            MsgPackError::Io(ref e) => write!(f, "i/o error reading data: {}", e),
+            MsgPackError::Parse(ref e) => write!(f, "invalid MessagePack: {}", e),
+        }
+    }
+}
 
 /// The `MsgPack` type: implements `FromData` and `Responder`, allowing you to easily
 /// consume and respond with MessagePack data.
@@ -66,9 +88,23 @@ impl<T> MsgPack<T> {
     }
 }
 
-/// Maximum size of MessagePack data is 1MB.
-/// TODO: Determine this size from some configuration parameter.
-const MAX_SIZE: u64 = 1048576;
+/// Default maximum size, in bytes, of incoming MessagePack data: 1MiB.
+const DEFAULT_MAX_SIZE: u64 = 1048576;
+
+/// Returns the maximum number of bytes to read for incoming MessagePack data,
+/// as configured by the `limits.msgpack` parameter in `Rocket.toml`, or
+/// `DEFAULT_MAX_SIZE` if the parameter isn't set or isn't a valid,
+/// non-negative integer.
+fn max_size(request: &Request) -> u64 {
+    match request.config().get_int("limits.msgpack") {
+        Ok(n) if n >= 0 => n as u64,
+        Ok(n) => {
+            warn_!("`limits.msgpack` is negative ({}); using default.", n);
+            DEFAULT_MAX_SIZE
+        }
+        Err(_) => DEFAULT_MAX_SIZE,
+    }
+}
 
 /// Accepted content types are:
 /// `application/msgpack`, `application/x-msgpack`, `bin/msgpack`, and `bin/x-msgpack`
@@ -86,18 +122,30 @@ impl<T: Deserialize> FromData for MsgPack<T> {
             return Outcome::Forward(data);
         }
 
+        let limit = max_size(request);
+        let mut reader = data.open().take(limit);
         let mut buf = Vec::new();
-        if let Err(e) = data.open().take(MAX_SIZE).read_to_end(&mut buf) {
-            let e = MsgPackError::InvalidDataRead(e);
+        if let Err(e) = reader.by_ref().read_to_end(&mut buf) {
             error_!("Couldn't read request data: {:?}", e);
-            return Outcome::Failure((Status::BadRequest, e));
-        };
+            return Outcome::Failure((Status::BadRequest, MsgPackError::Io(e)));
+        }
+
+        // If we read exactly up to the limit, the body may have been
+        // truncated; check whether any data remains beyond the cap.
+        if buf.len() as u64 == limit {
+            let mut probe = [0u8; 1];
+            let truncated = reader.into_inner().read(&mut probe).map(|n| n > 0).unwrap_or(false);
+            if truncated {
+                error_!("MsgPack data exceeds the {} byte size limit.", limit);
+                return Outcome::Failure((Status::PayloadTooLarge, MsgPackError::TooLarge));
+            }
+        }
 
         match rmp_serde::from_slice(&buf).map(|val| MsgPack(val)) {
             Ok(value) => Outcome::Success(value),
             Err(e) => {
                 error_!("Couldn't parse MessagePack body: {:?}", e);
-                Outcome::Failure((Status::BadRequest, e))
+                Outcome::Failure((Status::BadRequest, MsgPackError::Parse(e)))
             }
         }
     }
@@ -113,6 +161,7 @@ impl<T: Serialize> Responder<'static> for MsgPack<T> {
             Status::InternalServerError
         }).and_then(|buf| {
             Response::build()
+                .header(ContentType::new("application", "msgpack"))
                 .sized_body(Cursor::new(buf))
                 .ok()
         })
@@ -132,3 +181,65 @@ impl<T> DerefMut for MsgPack<T> {
         &mut self.0
     }
 }
+
+/// Like [`MsgPack`], but serializes structs as maps keyed by field name
+/// instead of the default compact array encoding.
+///
+/// This is useful when the response will be decoded by a consumer that
+/// doesn't share Rocket's struct layout and so keys on field names instead,
+/// such as a JavaScript or Python client. Prefer `MsgPack` unless you need
+/// this wire format, since named encoding is both larger and slower to
+/// produce.
+///
+/// ```rust,ignore
+/// #[get("/users/<id>")]
+/// fn user(id: usize) -> NamedMsgPack<User> {
+///     let user_from_id = User::from(id);
+///     ...
+///     NamedMsgPack(user_from_id)
+/// }
+/// ```
+///
+/// `NamedMsgPack` only implements `Responder`; incoming data should continue
+/// to be parsed with `MsgPack`, whose `FromData` implementation accepts
+/// both the compact and named encodings.
+#[derive(Debug)]
+pub struct NamedMsgPack<T>(pub T);
+
+impl<T> NamedMsgPack<T> {
+    /// Consumes the `NamedMsgPack` wrapper and returns the wrapped item.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Serializes the wrapped value into MessagePack, encoding structs as maps
+/// keyed by field name. If serialization fails, an `Err` of
+/// `Status::InternalServerError` is returned.
+impl<T: Serialize> Responder<'static> for NamedMsgPack<T> {
+    fn respond(self) -> response::Result<'static> {
+        rmp_serde::to_vec_named(&self.0).map_err(|e| {
+            error_!("NamedMsgPack failed to serialize: {:?}", e);
+            Status::InternalServerError
+        }).and_then(|buf| {
+            Response::build()
+                .header(ContentType::new("application", "msgpack"))
+                .sized_body(Cursor::new(buf))
+                .ok()
+        })
+    }
+}
+
+impl<T> Deref for NamedMsgPack<T> {
+    type Target = T;
+
+    fn deref<'a>(&'a self) -> &'a T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for NamedMsgPack<T> {
+    fn deref_mut<'a>(&'a mut self) -> &'a mut T {
+        &mut self.0
+    }
+}
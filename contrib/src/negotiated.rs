@@ -0,0 +1,119 @@
+use rocket::outcome::Outcome;
+use rocket::request::{self, Request, FromRequest};
+use rocket::response::{self, Responder};
+
+use serde::Serialize;
+
+use json::Json;
+use msgpack::MsgPack;
+
+/// Accepted JSON media types. Matching is case-insensitive, since media
+/// types are per RFC 7231.
+fn is_json_media_type(mt: &str) -> bool {
+    mt.eq_ignore_ascii_case("application/json") || mt == "*/*"
+}
+
+/// Accepted MessagePack media types; mirrors the set `MsgPack` itself reads.
+/// Matching is case-insensitive, since media types are per RFC 7231.
+fn is_msgpack_media_type(mt: &str) -> bool {
+    mt.eq_ignore_ascii_case("application/msgpack") || mt.eq_ignore_ascii_case("application/x-msgpack")
+        || mt.eq_ignore_ascii_case("bin/msgpack") || mt.eq_ignore_ascii_case("bin/x-msgpack")
+}
+
+/// Returns `true` if `accept` expresses a strict preference for a
+/// MessagePack media type over a JSON one, according to each media type's
+/// `q` value (default `1`, highest wins; JSON wins ties).
+fn prefers_msgpack(accept: &str) -> bool {
+    let mut best_msgpack_q: f32 = -1.0;
+    let mut best_json_q: f32 = -1.0;
+
+    for entry in accept.split(',') {
+        let mut pieces = entry.split(';');
+        let media_type = match pieces.next() {
+            Some(mt) => mt.trim(),
+            None => continue,
+        };
+
+        let q = pieces
+            .filter_map(|p| {
+                let p = p.trim();
+                if p.starts_with("q=") { p[2..].trim().parse::<f32>().ok() } else { None }
+            })
+            .next()
+            .unwrap_or(1.0);
+
+        if is_msgpack_media_type(media_type) && q > best_msgpack_q {
+            best_msgpack_q = q;
+        } else if is_json_media_type(media_type) && q > best_json_q {
+            best_json_q = q;
+        }
+    }
+
+    best_msgpack_q > best_json_q
+}
+
+/// A request guard that determines whether the client's `Accept` header
+/// prefers a MessagePack or a JSON response.
+///
+/// JSON is preferred whenever no `Accept` header is present, or when the
+/// header expresses no clear preference for MessagePack. This guard never
+/// fails; it always resolves to one of its two variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preferred {
+    /// The client prefers a MessagePack response.
+    MsgPack,
+    /// The client prefers a JSON response.
+    Json,
+}
+
+impl<'a, 'r> FromRequest<'a, 'r> for Preferred {
+    type Error = ();
+
+    fn from_request(request: &'a Request<'r>) -> request::Outcome<Self, Self::Error> {
+        let preferred = match request.headers().get_one("Accept") {
+            Some(accept) if prefers_msgpack(accept) => Preferred::MsgPack,
+            _ => Preferred::Json,
+        };
+
+        Outcome::Success(preferred)
+    }
+}
+
+/// A responder that serializes the wrapped `Serialize` value as either JSON
+/// or MessagePack, picking whichever the client's `Accept` header prefers.
+///
+/// Since `Responder::respond` has no access to the `Request`, the
+/// preference must be determined ahead of time with the [`Preferred`]
+/// request guard and passed in at construction:
+///
+/// ```rust,ignore
+/// #[get("/users/<id>")]
+/// fn user(id: usize, accept: Preferred) -> Negotiated<User> {
+///     let user_from_id = User::from(id);
+///     ...
+///     Negotiated::new(user_from_id, accept)
+/// }
+/// ```
+///
+/// The response's `Content-Type` is set to `application/json` or
+/// `application/msgpack` to match the chosen encoding.
+pub struct Negotiated<T> {
+    value: T,
+    preferred: Preferred,
+}
+
+impl<T> Negotiated<T> {
+    /// Wraps `value`, to be serialized according to `preferred`.
+    pub fn new(value: T, preferred: Preferred) -> Negotiated<T> {
+        Negotiated { value: value, preferred: preferred }
+    }
+}
+
+impl<T: Serialize> Responder<'static> for Negotiated<T> {
+    fn respond(self) -> response::Result<'static> {
+        match self.preferred {
+            Preferred::MsgPack => MsgPack(self.value).respond(),
+            Preferred::Json => Json(self.value).respond(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+extern crate regex;
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+use rocket::request::FromFormValue;
+
+use self::regex::Regex;
+
+lazy_static! {
+    /// Compiled patterns, keyed by the `Matcher` type they were compiled
+    /// for, so each pattern is only ever compiled once.
+    static ref PATTERNS: Mutex<HashMap<TypeId, Regex>> = Mutex::new(HashMap::new());
+}
+
+/// Trait implemented by zero-sized marker types that supply the regular
+/// expression used by [`Pattern`] to validate a form value.
+pub trait Matcher {
+    /// The pattern a form value must match in its entirety. The pattern is
+    /// implicitly anchored to the full string; it does not need (but may
+    /// contain) its own `^`/`$` anchors.
+    fn pattern() -> &'static str;
+}
+
+/// A validating wrapper that parses the raw form value and requires it to
+/// match `M`'s pattern in its entirety, returning the matched string on
+/// success.
+///
+/// Supply the pattern with a zero-sized marker type that implements
+/// [`Matcher`]:
+///
+/// ```rust
+/// use rocket::request::FromFormValue;
+/// use rocket_contrib::{Matcher, Pattern};
+///
+/// struct Slug;
+///
+/// impl Matcher for Slug {
+///     fn pattern() -> &'static str {
+///         r"[a-z0-9]+(-[a-z0-9]+)*"
+///     }
+/// }
+///
+/// # #[allow(dead_code)]
+/// struct Post<'r> {
+///     slug: Pattern<'r, Slug>,
+/// }
+/// ```
+///
+/// `M`'s pattern is compiled once and cached for the lifetime of the
+/// program, so repeated validation doesn't pay the cost of recompiling the
+/// regular expression on every form parse.
+pub struct Pattern<'v, M>(pub &'v str, PhantomData<M>);
+
+impl<'v, M> Pattern<'v, M> {
+    /// Consumes the `Pattern` wrapper and returns the wrapped, validated
+    /// string.
+    pub fn into_inner(self) -> &'v str {
+        self.0
+    }
+}
+
+impl<'v, M: Matcher + 'static> FromFormValue<'v> for Pattern<'v, M> {
+    type Error = &'v str;
+
+    fn from_form_value(v: &'v str) -> Result<Self, Self::Error> {
+        let mut cache = PATTERNS.lock().expect("patterns lock poisoned");
+        let regex = cache.entry(TypeId::of::<M>()).or_insert_with(|| {
+            let anchored = format!("^(?:{})$", M::pattern());
+            Regex::new(&anchored).expect("invalid regex pattern")
+        });
+
+        if regex.is_match(v) {
+            Ok(Pattern(v, PhantomData))
+        } else {
+            Err(v)
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr};
 use std::str::FromStr;
+use std::marker::PhantomData;
+use std::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize,
+               NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize};
+use std::time::Duration;
 
 use error::Error;
 use http::uri::URI;
@@ -53,7 +57,7 @@ use http::uri::URI;
 /// Rocket implements `FromFormValue` for many standard library types. Their
 /// behavior is documented here.
 ///
-///   * **f32, f64, isize, i8, i16, i32, i64, usize, u8, u16, u32, u64**
+///   * **f32, f64, isize, i8, i16, i32, i64, usize, u8, u16, u32, u64, char**
 ///
 ///   **IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr**
 ///
@@ -61,6 +65,26 @@ use http::uri::URI;
 ///     type returns successfully. Otherwise, the raw form value is returned as
 ///     the `Err` value.
 ///
+///   * **NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize,
+///     NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize**
+///
+///     A value is validated successfully if it parses as the underlying
+///     integer type and is not zero. Otherwise, the raw form value is
+///     returned as the `Err` value.
+///
+///   * **Duration**
+///
+///     A value is validated successfully if it parses as a `u64` number of
+///     seconds. Otherwise, the raw form value is returned as the `Err`
+///     value.
+///
+///   * **[T; N]** _where_ **T: FromFormValue, 1 <= N <= 8**
+///
+///     A value is validated successfully if it consists of exactly `N`
+///     comma-separated values, each of which validates via `T`'s
+///     `FromFormValue` implementation. Otherwise, the raw form value is
+///     returned as the `Err` value.
+///
 ///   * **bool**
 ///
 ///     A value is validated successfully as `true` if the the form value is
@@ -202,9 +226,84 @@ macro_rules! impl_with_fromstr {
     )+)
 }
 
-impl_with_fromstr!(f32, f64, isize, i8, i16, i32, i64, usize, u8, u16, u32, u64,
+impl_with_fromstr!(f32, f64, isize, i8, i16, i32, i64, usize, u8, u16, u32, u64, char,
     IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr);
 
+macro_rules! impl_with_nonzero {
+    ($($NZ:ident => $T:ident),+) => ($(
+        impl<'v> FromFormValue<'v> for $NZ {
+            type Error = &'v str;
+
+            fn from_form_value(v: &'v str) -> Result<Self, Self::Error> {
+                match $T::from_str(v) {
+                    Ok(n) => $NZ::new(n).ok_or(v),
+                    Err(_) => Err(v),
+                }
+            }
+        }
+    )+)
+}
+
+impl_with_nonzero!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64,
+    NonZeroUsize => usize, NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32,
+    NonZeroI64 => i64, NonZeroIsize => isize
+);
+
+/// A value is validated successfully if it can be parsed as a `u64` number
+/// of seconds. Otherwise, the raw form value is returned as the `Err` value.
+impl<'v> FromFormValue<'v> for Duration {
+    type Error = &'v str;
+
+    fn from_form_value(v: &'v str) -> Result<Self, Self::Error> {
+        match u64::from_str(v) {
+            Ok(secs) => Ok(Duration::from_secs(secs)),
+            Err(_) => Err(v),
+        }
+    }
+}
+
+macro_rules! impl_for_array {
+    ($($N:expr => ($($idx:tt),+)),+ $(,)*) => ($(
+        /// A value is validated successfully if it contains exactly `N`
+        /// comma-separated values, each of which validates successfully via
+        /// `T::from_form_value`. Otherwise, the raw form value is returned
+        /// as the `Err` value.
+        impl<'v, T: FromFormValue<'v>> FromFormValue<'v> for [T; $N] {
+            type Error = &'v str;
+
+            fn from_form_value(v: &'v str) -> Result<Self, Self::Error> {
+                let mut parts = v.split(',');
+                let mut values = Vec::with_capacity($N);
+                for part in parts.by_ref().take($N) {
+                    match T::from_form_value(part) {
+                        Ok(value) => values.push(value),
+                        Err(_) => return Err(v),
+                    }
+                }
+
+                if values.len() != $N || parts.next().is_some() {
+                    return Err(v);
+                }
+
+                let mut values = values.into_iter();
+                Ok([$({ let _ = $idx; values.next().unwrap() }),+])
+            }
+        }
+    )+)
+}
+
+impl_for_array! {
+    1 => (0),
+    2 => (0, 1),
+    3 => (0, 1, 2),
+    4 => (0, 1, 2, 3),
+    5 => (0, 1, 2, 3, 4),
+    6 => (0, 1, 2, 3, 4, 5),
+    7 => (0, 1, 2, 3, 4, 5, 6),
+    8 => (0, 1, 2, 3, 4, 5, 6, 7),
+}
+
 impl<'v, T: FromFormValue<'v>> FromFormValue<'v> for Option<T> {
     type Error = Error;
 
@@ -220,7 +319,6 @@ impl<'v, T: FromFormValue<'v>> FromFormValue<'v> for Option<T> {
     }
 }
 
-// TODO: Add more useful implementations (range, regex, etc.).
 impl<'v, T: FromFormValue<'v>> FromFormValue<'v> for Result<T, T::Error> {
     type Error = Error;
 
@@ -232,3 +330,67 @@ impl<'v, T: FromFormValue<'v>> FromFormValue<'v> for Result<T, T::Error> {
     }
 }
 
+/// Trait implemented by zero-sized marker types that supply the inclusive
+/// bounds accepted by [`Range`]. `FromFormValue::from_form_value` has no
+/// access to instance state, so the bounds are carried by a marker type
+/// parameter instead of by a constructor argument.
+pub trait Bounds<T> {
+    /// The smallest value `Range` will accept, inclusive.
+    const MIN: T;
+    /// The largest value `Range` will accept, inclusive.
+    const MAX: T;
+}
+
+/// A validating wrapper that parses a `T: FromFormValue` and rejects values
+/// outside of `[B::MIN, B::MAX]`.
+///
+/// Supply the bounds with a zero-sized marker type that implements
+/// [`Bounds`]:
+///
+/// ```rust
+/// use rocket::request::{FromFormValue, Bounds, Range};
+///
+/// struct AgeBounds;
+///
+/// impl Bounds<u16> for AgeBounds {
+///     const MIN: u16 = 0;
+///     const MAX: u16 = 150;
+/// }
+///
+/// # #[allow(dead_code)]
+/// struct Person {
+///     age: Range<u16, AgeBounds>,
+/// }
+/// ```
+///
+/// As with the other `FromFormValue` implementations, the raw, unparsed
+/// form value is returned in the `Err` variant when validation fails,
+/// whether because the value couldn't be parsed as `T` or because it fell
+/// outside of the allowed range.
+pub struct Range<T, B>(pub T, PhantomData<B>);
+
+impl<T, B> Range<T, B> {
+    /// Consumes the `Range` wrapper and returns the wrapped, validated
+    /// value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'v, T, B> FromFormValue<'v> for Range<T, B>
+    where T: FromFormValue<'v, Error = &'v str> + PartialOrd, B: Bounds<T>
+{
+    type Error = &'v str;
+
+    fn from_form_value(v: &'v str) -> Result<Self, Self::Error> {
+        match T::from_form_value(v) {
+            Ok(value) => if value >= B::MIN && value <= B::MAX {
+                Ok(Range(value, PhantomData))
+            } else {
+                Err(v)
+            },
+            Err(_) => Err(v),
+        }
+    }
+}
+
@@ -0,0 +1,3 @@
+mod from_form_value;
+
+pub use self::from_form_value::{FromFormValue, Bounds, Range};
@@ -0,0 +1,3 @@
+mod form;
+
+pub use self::form::{FromFormValue, Bounds, Range};